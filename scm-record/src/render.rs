@@ -9,6 +9,8 @@ use tui::style::{Color, Modifier, Style};
 use tui::text::Span;
 use tui::widgets::StatefulWidget;
 use tui::Frame;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 use crate::util::{IsizeExt, UsizeExt};
 
@@ -181,6 +183,57 @@ impl<ComponentId> Default for DrawTrace<ComponentId> {
     }
 }
 
+/// Which pass of the two-phase render (see `Viewport::render_top_level`) is
+/// currently executing.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ViewportMode {
+    /// Components register hitboxes via `insert_hitbox`, but nothing is
+    /// written to the `Buffer` yet. Hover state derived from a stale previous
+    /// frame would flicker, so hitboxes must be known for the *current*
+    /// frame before anything is painted.
+    Layout,
+
+    /// Components draw for real. `Viewport::is_hovered` can be queried
+    /// against the hitboxes collected during the `Layout` pass.
+    Paint,
+}
+
+/// Cached per-component geometry produced by a `Layout` pass, keyed by
+/// component ID, used to skip recomputing (and redrawing) the subtree rooted
+/// at a component whose `Component::should_update` returns `false`.
+///
+/// Each entry for a given ID covers that component's *entire* subtree (itself
+/// and every descendant), mirroring what `DrawTrace::components` accumulates
+/// while actually drawing it, so that skipping a component reproduces exactly
+/// what drawing it would have recorded — both for hit-testing (`hitboxes`)
+/// and for the `drawn_rects` map used for event routing (`rects`).
+#[derive(Debug)]
+pub(crate) struct DamageCache<ComponentId> {
+    /// For each component ID, the bounding box of it and every descendant, as
+    /// last computed by a real (non-skipped) draw of that component.
+    rects: HashMap<ComponentId, HashMap<ComponentId, Rect>>,
+    /// For each component ID, the hitboxes (in paint order) registered by it
+    /// and its descendants the last time it was really drawn.
+    hitboxes: HashMap<ComponentId, Vec<(ComponentId, Rect)>>,
+    /// A full snapshot of the `Buffer` as it looked after the `Paint` pass
+    /// that produced this `DamageCache`. The terminal backend double-buffers
+    /// and resets the *inactive* buffer after each flush, so the `Buffer`
+    /// backing the next frame starts blank rather than carrying over this
+    /// frame's cells; skipping a component's `draw` call therefore has
+    /// nothing to reuse unless we blit its cells back in from here.
+    buf: Buffer,
+}
+
+impl<ComponentId> Default for DamageCache<ComponentId> {
+    fn default() -> Self {
+        Self {
+            rects: Default::default(),
+            hitboxes: Default::default(),
+            buf: Default::default(),
+        }
+    }
+}
+
 /// Accessor to draw on the virtual canvas. The caller can draw anywhere on the
 /// canvas, but the actual renering will be restricted to this viewport. All
 /// draw calls are also tracked so that we know where each component was drawn
@@ -191,6 +244,36 @@ pub(crate) struct Viewport<'a, ComponentId> {
     rect: Rect,
     trace: Vec<DrawTrace<ComponentId>>,
     debug_messages: Vec<String>,
+    mode: ViewportMode,
+    /// Hitboxes registered via `insert_hitbox` during the `Layout` pass, in
+    /// paint order (i.e. stacking order — later entries are drawn on top).
+    hitboxes: Vec<(ComponentId, Rect)>,
+    /// The current mouse position on the virtual canvas, if known.
+    mouse_position: Option<(isize, isize)>,
+    /// The winning `(Position, CursorKind)` reported by `Component::cursor`
+    /// so far, if any.
+    cursor: Option<(Position, CursorKind)>,
+    /// The absolute canvas position that `(0, 0)` refers to for the
+    /// currently-active layer. See `push_layer`.
+    origin: (isize, isize),
+    /// Saved `(origin, trace)` pairs for layers enclosing the currently-active
+    /// one, restored by `pop_layer`.
+    layers: Vec<((isize, isize), Vec<DrawTrace<ComponentId>>)>,
+    /// Geometry to reuse for components whose `should_update` returns
+    /// `false`. During the `Layout` pass, this is the `DamageCache` produced
+    /// by the *previous frame's* `Layout` pass (so a component that's been
+    /// unchanged for several frames in a row keeps working from then-current
+    /// data). During the `Paint` pass, this is instead the `DamageCache`
+    /// produced by *this same frame's* `Layout` pass, so that a skipped
+    /// component's hitbox (used for hover/click) and its `drawn_rects` entry
+    /// (used for painting position and event routing) always agree.
+    previous: Option<&'a DamageCache<ComponentId>>,
+    /// Geometry collected for reuse by a later frame's `Layout` pass. Only
+    /// populated during the `Layout` pass.
+    damage_cache: DamageCache<ComponentId>,
+    /// The union of the bounding boxes of every component that was actually
+    /// redrawn this frame (i.e. wasn't skipped via damage tracking).
+    dirty: Rect,
 }
 
 impl<'a, ComponentId: Clone + Debug + Eq + Hash> Viewport<'a, ComponentId> {
@@ -202,19 +285,61 @@ impl<'a, ComponentId: Clone + Debug + Eq + Hash> Viewport<'a, ComponentId> {
         }
     }
 
-    /// Render the provided component using the given `Frame`. Returns a mapping
-    /// indicating where each component was drawn on the screen.
+    /// Render the provided component using the given `Frame`. Returns a
+    /// mapping indicating where each component was drawn on the screen, the
+    /// winning `(Position, CursorKind)` reported by `Component::cursor` (if
+    /// any — the caller should use this to call the backend's
+    /// `set_cursor`/`hide_cursor`), and the damage `Rect`: the union of the
+    /// bounding boxes of every component that was actually redrawn, which the
+    /// caller can use to restrict the terminal diff/flush to the region that
+    /// changed.
+    ///
+    /// `previous` should be the fourth element of the tuple returned by the
+    /// previous call to this function, if any; it's consulted by the
+    /// `Layout` pass to skip recomputing (and the `Paint` pass to skip
+    /// redrawing) components whose `Component::should_update` returns
+    /// `false`. A skipped component adds nothing to the damage `Rect` itself,
+    /// but its bounding box still falls inside that `Rect` whenever an
+    /// enclosing component is redrawn, since the enclosing component's
+    /// bounding box contains it. The returned
+    /// `DamageCache` should be passed as `previous` on the *next* call.
+    ///
+    /// This runs two passes over the component tree: a `Layout` pass, which
+    /// lets components register hitboxes (see `insert_hitbox`) without
+    /// touching the `Buffer`, followed by a `Paint` pass which does the real
+    /// drawing. Both passes consult the *same* `DamageCache` for a given
+    /// frame — the `Layout` pass reads last frame's, and produces the one the
+    /// `Paint` pass reads — so a skipped component's hitbox and its
+    /// `drawn_rects` entry always describe the same geometry.
     pub fn render_top_level<C: Component>(
         frame: &mut Frame<impl Backend>,
         x: isize,
         y: isize,
+        mouse_position: Option<(isize, isize)>,
+        previous: Option<&DamageCache<C::Id>>,
         component: &C,
-    ) -> HashMap<C::Id, Rect> {
-        let widget = TopLevelWidget { component, x, y };
+    ) -> (
+        HashMap<C::Id, Rect>,
+        Option<(Position, CursorKind)>,
+        Rect,
+        DamageCache<C::Id>,
+    ) {
+        let widget = TopLevelWidget {
+            component,
+            x,
+            y,
+            mouse_position,
+            previous,
+        };
         let term_area = frame.size();
-        let mut drawn_rects = Default::default();
-        frame.render_stateful_widget(widget, term_area, &mut drawn_rects);
-        drawn_rects
+        let mut state = TopLevelState::default();
+        frame.render_stateful_widget(widget, term_area, &mut state);
+        (
+            state.drawn_rects,
+            state.cursor,
+            state.dirty,
+            state.damage_cache,
+        )
     }
 
     fn current_trace_mut(&mut self) -> &mut DrawTrace<ComponentId> {
@@ -222,11 +347,115 @@ impl<'a, ComponentId: Clone + Debug + Eq + Hash> Viewport<'a, ComponentId> {
         .expect("draw trace stack is empty, so can't update trace for current component; did you call `Viewport::render_top_level` to render the top-level component?")
     }
 
+    /// Offset `rect` by the currently-active layer's origin, converting it
+    /// from layer-local to absolute canvas coordinates.
+    fn apply_origin(&self, rect: Rect) -> Rect {
+        let (origin_x, origin_y) = self.origin;
+        Rect {
+            x: rect.x + origin_x,
+            y: rect.y + origin_y,
+            ..rect
+        }
+    }
+
+    /// Start a new layer: an absolutely-positioned region, anchored at
+    /// `origin`, drawn on top of everything drawn so far. `origin` is given
+    /// in absolute canvas coordinates, e.g. the `Rect` of a child component
+    /// returned by `draw_component`, so that a popup can be anchored to it.
+    ///
+    /// While a layer is active, coordinates passed to `draw_span`,
+    /// `draw_component`, `set_style`, and `insert_hitbox` are relative to
+    /// `origin` rather than the enclosing layer's origin, and the layer gets
+    /// its own `DrawTrace`, independent of the enclosing layer's. Because
+    /// layers share the same `Buffer` and are drawn in `push_layer` order,
+    /// later layers paint on top of earlier ones for free. Must be paired
+    /// with a later call to `pop_layer`, which merges the layer's components
+    /// back into the enclosing scope so they're reachable by event dispatch
+    /// like any other component (see `pop_layer`).
+    pub fn push_layer(&mut self, origin: (isize, isize)) {
+        let parent_trace = std::mem::replace(&mut self.trace, vec![Default::default()]);
+        self.layers.push((self.origin, parent_trace));
+        self.origin = origin;
+    }
+
+    /// End the layer started by the last unmatched call to `push_layer`,
+    /// returning where each of its components was drawn (in the same style
+    /// as the return value of `Viewport::render_top_level`). The layer's
+    /// trace is also merged into the enclosing scope's, so the layer's
+    /// components show up in the `drawn_rects` that `render_top_level`
+    /// ultimately returns — and are therefore reachable by `dispatch_event`
+    /// — exactly as if they'd been drawn directly into the enclosing scope.
+    pub fn pop_layer(&mut self) -> HashMap<ComponentId, Rect> {
+        let layer_trace = self.trace.pop().expect(
+            "layer's draw trace stack is empty; did a `draw_component` call leak out of the layer?",
+        );
+        debug_assert!(self.trace.is_empty());
+        let (origin, parent_trace) = self
+            .layers
+            .pop()
+            .expect("pop_layer called without a matching push_layer");
+        self.origin = origin;
+        self.trace = parent_trace;
+        let components = layer_trace.components.clone();
+        self.current_trace_mut().merge(layer_trace);
+        components
+    }
+
+    /// Record a hitbox for the component identified by `id`, at the given
+    /// `rect`. This should be called during the `Layout` pass; later calls
+    /// are considered to be "on top" of earlier ones for the purposes of
+    /// `is_hovered`. A no-op outside of the `Layout` pass, so that components
+    /// can unconditionally call this from the same code path used for both
+    /// passes.
+    pub fn insert_hitbox(&mut self, id: ComponentId, rect: Rect) {
+        if self.mode == ViewportMode::Layout {
+            self.hitboxes.push((id, self.apply_origin(rect)));
+        }
+    }
+
+    /// Whether the component identified by `id` is currently hovered, i.e.
+    /// its hitbox (as registered via `insert_hitbox`) contains the current
+    /// mouse position, and no hitbox registered later (and therefore painted
+    /// on top of it) also contains the mouse position.
+    pub fn is_hovered(&self, id: &ComponentId) -> bool {
+        let Some((mouse_x, mouse_y)) = self.mouse_position else {
+            return false;
+        };
+        let point = Rect {
+            x: mouse_x,
+            y: mouse_y,
+            width: 1,
+            height: 1,
+        };
+        self.hitboxes
+            .iter()
+            .rev()
+            .find(|(_id, rect)| !rect.intersect(point).is_empty())
+            .is_some_and(|(topmost_id, _rect)| topmost_id == id)
+    }
+
+    /// Record `cursor` as the winner, if no component drawn more deeply in
+    /// the current subtree has already claimed the cursor this frame. Since
+    /// `draw_component` visits a component's children before consulting the
+    /// component's own `Component::cursor`, a descendant's claim beats the
+    /// component's own. Claims made before the current subtree started are
+    /// set aside by `draw_component` and only restored if the subtree makes
+    /// no claim, so among siblings the one drawn last (topmost) wins,
+    /// mirroring `is_hovered`.
+    fn claim_cursor(&mut self, cursor: Option<(Position, CursorKind)>) {
+        if self.cursor.is_none() {
+            self.cursor = cursor;
+        }
+    }
+
     /// Set the terminal styling for a certain area. This can also be
     /// accomplished using `draw_span` with a styled `Span`, but in some cases,
     /// it may be more appropriate to set the style of certain cells directly.
     pub fn set_style(&mut self, rect: Rect, style: Style) {
-        self.buf.set_style(self.translate_rect(rect), style);
+        let rect = self.apply_origin(rect);
+        if self.mode == ViewportMode::Paint {
+            self.buf.set_style(self.translate_rect(rect), style);
+        }
         self.current_trace_mut().merge_rect(rect);
     }
 
@@ -237,12 +466,71 @@ impl<'a, ComponentId: Clone + Debug + Eq + Hash> Viewport<'a, ComponentId> {
 
     /// Draw the provided child component to the screen at the given `(x, y)`
     /// location.
+    ///
+    /// If `component.should_update()` is `false` and `component` was also
+    /// drawn the last time its subtree actually ran, this skips calling
+    /// `component.draw` entirely (in *both* the `Layout` and `Paint` passes,
+    /// so the expensive call is genuinely avoided, not just its writes to the
+    /// `Buffer`) and instead reuses the cached `DamageCache` entry for
+    /// `component`'s ID: its own `Rect`, every descendant's `Rect` (so
+    /// `drawn_rects` stays complete for event routing even though none of
+    /// them were visited this frame), and — during `Layout` — its hitboxes.
+    /// The component then adds nothing to the damage `Rect` returned by
+    /// `render_top_level` itself; an enclosing component that is redrawn
+    /// still adds its whole bounding box, which contains this one.
     pub fn draw_component<C: Component<Id = ComponentId>>(
         &mut self,
         x: isize,
         y: isize,
         component: &C,
     ) -> Rect {
+        let id = component.id();
+
+        if !component.should_update() {
+            if let Some(subtree_rects) = self.previous.and_then(|cache| cache.rects.get(&id)) {
+                let prev_rect = subtree_rects[&id];
+                let subtree_rects = subtree_rects.clone();
+                let subtree_hitboxes = self
+                    .previous
+                    .and_then(|cache| cache.hitboxes.get(&id))
+                    .cloned();
+
+                let current_trace = self.current_trace_mut();
+                current_trace.merge_rect(prev_rect);
+                for (descendant_id, descendant_rect) in &subtree_rects {
+                    current_trace
+                        .components
+                        .insert(descendant_id.clone(), *descendant_rect);
+                }
+
+                if self.mode == ViewportMode::Layout {
+                    if let Some(subtree_hitboxes) = &subtree_hitboxes {
+                        self.hitboxes.extend(subtree_hitboxes.iter().cloned());
+                    }
+                    // Propagate the reused entry forward so it remains
+                    // available even if `component` is skipped again on the
+                    // next several frames in a row.
+                    self.damage_cache.rects.insert(id.clone(), subtree_rects);
+                    if let Some(subtree_hitboxes) = subtree_hitboxes {
+                        self.damage_cache.hitboxes.insert(id, subtree_hitboxes);
+                    }
+                } else {
+                    // The `Buffer` backing this pass doesn't carry over
+                    // `component`'s cells from the last time it actually
+                    // drew (see `DamageCache::buf`), so blit them back in
+                    // from the snapshot instead of leaving them blank.
+                    self.blit_previous(prev_rect);
+                }
+
+                // Drawn after any earlier siblings, so its claim (if any)
+                // overrides theirs.
+                self.cursor = component.cursor(prev_rect).or(self.cursor);
+                return prev_rect;
+            }
+        }
+
+        let hitbox_start = self.hitboxes.len();
+        let outer_cursor = self.cursor.take();
         self.trace.push(Default::default());
         component.draw(self, x, y);
         let mut trace = self.trace.pop().unwrap();
@@ -252,42 +540,65 @@ impl<'a, ComponentId: Clone + Debug + Eq + Hash> Viewport<'a, ComponentId> {
             .values()
             .fold(trace.rect, |acc, elem| acc.union_bounding(*elem));
         trace.rect = trace_rect;
-        trace.components.insert(component.id(), trace_rect);
+        trace.components.insert(id.clone(), trace_rect);
+
+        if self.mode == ViewportMode::Layout {
+            self.damage_cache
+                .rects
+                .insert(id.clone(), trace.components.clone());
+            let subtree_hitboxes = self.hitboxes[hitbox_start..].to_vec();
+            self.damage_cache.hitboxes.insert(id, subtree_hitboxes);
+        }
+
+        // Descendants' own `draw_component` calls (and therefore their
+        // `claim_cursor` calls) complete before we get here, so by the time
+        // we check, `self.cursor` is already `Some` if any descendant wants
+        // the cursor. Only claim it on `component`'s behalf if none of them
+        // did, so the innermost (topmost) component wins, matching
+        // `is_hovered`. If nothing in this subtree claimed it, fall back to
+        // whatever an earlier sibling claimed.
+        self.claim_cursor(component.cursor(trace_rect));
+        self.cursor = self.cursor.or(outer_cursor);
+        if self.mode == ViewportMode::Paint {
+            self.dirty = self.dirty.union_bounding(trace_rect);
+        }
 
         self.current_trace_mut().merge(trace);
         trace_rect
     }
 
     /// Draw a `Span` directly to the screen at the given `(x, y)` location.
+    /// The `Rect`'s width is the span's on-screen display width (in terminal
+    /// columns), not its `char` count, so that wide (e.g. CJK) characters are
+    /// accounted for correctly.
     pub fn draw_span(&mut self, x: isize, y: isize, span: &Span) -> Rect {
         let Span { content, style } = span;
-        let span_rect = Rect {
+        let span_rect = self.apply_origin(Rect {
             x,
             y,
-            // FIXME: probably not Unicode-safe
-            width: content.chars().count(),
+            width: content.width(),
             height: 1,
-        };
+        });
         self.current_trace_mut().merge_rect(span_rect);
 
         let draw_rect = self.rect.intersect(span_rect);
-        if !draw_rect.is_empty() {
-            let span_start_idx = (draw_rect.x - span_rect.x).unwrap_usize();
-            let span_start_byte_idx = content
-                .char_indices()
-                .nth(span_start_idx)
-                .map(|(i, _c)| i)
-                .unwrap_or(0);
-            let span_end_byte_idx = match content
-                .char_indices()
-                .nth(span_start_idx + draw_rect.width)
-                .map(|(i, _c)| i)
-            {
-                Some(span_end_byte_index) => span_end_byte_index,
-                None => content.len(),
+        if self.mode == ViewportMode::Paint && !draw_rect.is_empty() {
+            let clip_start_col = (draw_rect.x - span_rect.x).unwrap_usize();
+            let clip_end_col = clip_start_col + draw_rect.width;
+            let (byte_range, left_pad, right_pad) =
+                clip_to_columns(content, clip_start_col, clip_end_col);
+            let draw_content: Cow<str> = if left_pad == 0 && right_pad == 0 {
+                Cow::Borrowed(&content.as_ref()[byte_range])
+            } else {
+                // A double-width grapheme cluster straddles a clip edge; we
+                // can't split it in half, so pad with blanks instead.
+                let mut owned = " ".repeat(left_pad);
+                owned.push_str(&content.as_ref()[byte_range]);
+                owned.push_str(&" ".repeat(right_pad));
+                Cow::Owned(owned)
             };
             let draw_span = Span {
-                content: Cow::Borrowed(&content.as_ref()[span_start_byte_idx..span_end_byte_idx]),
+                content: draw_content,
                 style: *style,
             };
 
@@ -299,6 +610,35 @@ impl<'a, ComponentId: Clone + Debug + Eq + Hash> Viewport<'a, ComponentId> {
         span_rect
     }
 
+    /// Copy `rect`'s cells from the previous frame's `Buffer` snapshot (see
+    /// `DamageCache::buf`) into the current `Buffer`, in place of actually
+    /// drawing. Only meaningful during the `Paint` pass. Assumes `rect` means
+    /// the same thing in both buffers, i.e. that the viewport hasn't been
+    /// resized or rescrolled since the snapshot was taken — the same
+    /// assumption `should_update` already relies on for reusing `rect`
+    /// itself.
+    fn blit_previous(&mut self, rect: Rect) {
+        let Some(prev_buf) = self.previous.map(|cache| &cache.buf) else {
+            return;
+        };
+        let draw_rect = self.rect.intersect(rect);
+        if draw_rect.is_empty() {
+            return;
+        }
+        let buf_rect = self.translate_rect(draw_rect);
+        for y in buf_rect.y..buf_rect.y + buf_rect.height {
+            for x in buf_rect.x..buf_rect.x + buf_rect.width {
+                let in_bounds = x >= prev_buf.area.x
+                    && x < prev_buf.area.x + prev_buf.area.width
+                    && y >= prev_buf.area.y
+                    && y < prev_buf.area.y + prev_buf.area.height;
+                if in_bounds {
+                    *self.buf.get_mut(x, y) = prev_buf.get(x, y).clone();
+                }
+            }
+        }
+    }
+
     /// Convert the virtual `Rect` being displayed on the viewport, potentially
     /// including an area off-screen, into a real terminal `tui::layout::Rect`
     /// indicating the actual positions of the characters to be printed
@@ -318,32 +658,160 @@ impl<'a, ComponentId: Clone + Debug + Eq + Hash> Viewport<'a, ComponentId> {
     }
 }
 
+/// Find the byte range of `content` covering display columns
+/// `[start_col, end_col)`, along with the number of blank columns that should
+/// be padded on the left/right because a grapheme cluster of display width 2
+/// straddled a clip edge and couldn't be split without corrupting it.
+fn clip_to_columns(
+    content: &str,
+    start_col: usize,
+    end_col: usize,
+) -> (std::ops::Range<usize>, usize, usize) {
+    let mut col = 0;
+    let mut start_byte = content.len();
+    let mut end_byte = content.len();
+    let mut left_pad = 0;
+    let mut right_pad = 0;
+    let mut started = false;
+
+    for (byte_idx, grapheme) in content.grapheme_indices(true) {
+        if col >= end_col {
+            end_byte = byte_idx;
+            break;
+        }
+        let grapheme_width = grapheme.width();
+        if !started {
+            if col + grapheme_width <= start_col {
+                col += grapheme_width;
+                continue;
+            }
+            started = true;
+            start_byte = byte_idx;
+            if col < start_col {
+                left_pad = start_col - col;
+                start_byte = byte_idx + grapheme.len();
+                col += grapheme_width;
+                continue;
+            }
+        }
+        if col + grapheme_width > end_col {
+            right_pad = end_col - col;
+            end_byte = byte_idx;
+            col = end_col;
+            break;
+        }
+        col += grapheme_width;
+    }
+    if !started {
+        start_byte = content.len();
+        end_byte = content.len();
+    }
+
+    (start_byte..end_byte, left_pad, right_pad)
+}
+
 /// Wrapper to render via `tui::Frame`.
-struct TopLevelWidget<'a, C> {
+struct TopLevelWidget<'a, C: Component> {
     component: &'a C,
     x: isize,
     y: isize,
+    mouse_position: Option<(isize, isize)>,
+    previous: Option<&'a DamageCache<C::Id>>,
+}
+
+/// Result of rendering a top-level component: where each component was
+/// drawn, the winning cursor position/shape (if any), the damage `Rect`
+/// covering every component that was actually redrawn, and the `DamageCache`
+/// to pass back in as `previous` on the next render.
+struct TopLevelState<Id> {
+    drawn_rects: HashMap<Id, Rect>,
+    cursor: Option<(Position, CursorKind)>,
+    dirty: Rect,
+    damage_cache: DamageCache<Id>,
+}
+
+impl<Id> Default for TopLevelState<Id> {
+    fn default() -> Self {
+        Self {
+            drawn_rects: Default::default(),
+            cursor: None,
+            dirty: Default::default(),
+            damage_cache: Default::default(),
+        }
+    }
 }
 
 impl<C: Component> StatefulWidget for TopLevelWidget<'_, C> {
-    type State = HashMap<C::Id, Rect>;
+    type State = TopLevelState<C::Id>;
 
     fn render(self, area: tui::layout::Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let Self { component, x, y } = self;
-        let trace = vec![Default::default()];
+        let Self {
+            component,
+            x,
+            y,
+            mouse_position,
+            previous,
+        } = self;
+        let rect = Rect {
+            x,
+            y,
+            width: area.width.into(),
+            height: area.height.into(),
+        };
+
+        // Phase one: layout. Let components register hitboxes against the
+        // current frame's geometry without touching `buf`, consulting last
+        // frame's `DamageCache` to skip components whose `should_update`
+        // returns `false`.
+        let mut layout_viewport = Viewport::<C::Id> {
+            buf,
+            rect,
+            trace: vec![Default::default()],
+            debug_messages: Default::default(),
+            mode: ViewportMode::Layout,
+            hitboxes: Default::default(),
+            mouse_position,
+            cursor: None,
+            origin: (0, 0),
+            layers: Default::default(),
+            previous,
+            damage_cache: Default::default(),
+            dirty: Default::default(),
+        };
+        layout_viewport.draw_component(0, 0, component);
+        let hitboxes = layout_viewport.hitboxes;
+        let mut damage_cache = layout_viewport.damage_cache;
+        // The `Layout` pass never touches `buf`, so `damage_cache.buf` is
+        // still blank. Borrow last frame's buffer snapshot forward so the
+        // `Paint` pass below has real cells to blit for skipped components;
+        // it gets overwritten with this frame's own snapshot once painting
+        // is done.
+        damage_cache.buf = previous.map_or_else(Buffer::default, |cache| cache.buf.clone());
+
+        // Phase two: paint. Draw for real; `Viewport::is_hovered` can now be
+        // answered using the hitboxes collected above. Consult the
+        // `DamageCache` this same `Layout` pass just produced (rather than
+        // last frame's) so a skipped component's reused `Rect` always agrees
+        // with the hitbox already recorded for it above.
         let mut viewport = Viewport::<C::Id> {
             buf,
-            rect: Rect {
-                x,
-                y,
-                width: area.width.into(),
-                height: area.height.into(),
-            },
-            trace,
+            rect,
+            trace: vec![Default::default()],
             debug_messages: Default::default(),
+            mode: ViewportMode::Paint,
+            hitboxes,
+            mouse_position,
+            cursor: None,
+            origin: (0, 0),
+            layers: Default::default(),
+            previous: Some(&damage_cache),
+            damage_cache: Default::default(),
+            dirty: Default::default(),
         };
         viewport.draw_component(0, 0, component);
-        *state = viewport.trace.pop().unwrap().components;
+        state.drawn_rects = viewport.trace.pop().unwrap().components;
+        state.cursor = viewport.cursor;
+        state.dirty = viewport.dirty;
         debug_assert!(viewport.trace.is_empty());
 
         // Render debug messages.
@@ -378,9 +846,77 @@ impl<C: Component> StatefulWidget for TopLevelWidget<'_, C> {
                 }
             }
         }
+
+        // Snapshot the buffer as it looks after this frame's `Paint` pass, so
+        // a future frame that skips `component` can blit these cells back in
+        // (see `Viewport::blit_previous`) instead of reusing the stale
+        // pre-paint snapshot borrowed forward above.
+        damage_cache.buf = viewport.buf.clone();
+        state.damage_cache = damage_cache;
     }
 }
 
+/// Whether a component consumed an event, or is declining to handle it so
+/// that it can be routed elsewhere (e.g. bubbled up to its parent).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum EventResult {
+    /// The component didn't handle the event. The caller should try routing
+    /// it to the component's parent instead.
+    Ignored,
+
+    /// The component handled the event. No further routing should happen.
+    Consumed,
+}
+
+/// A key press, decoupled from any particular terminal backend's key-event
+/// type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum KeyCode {
+    Char(char),
+    Enter,
+    Esc,
+    Up,
+    Down,
+    Left,
+    Right,
+    Tab,
+    Backspace,
+}
+
+/// An input event to be routed to a [`Component`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Event {
+    /// A mouse click at the given virtual canvas position.
+    Click { x: isize, y: isize },
+
+    /// A key press, routed by `dispatch_event` to whichever component ID is
+    /// passed as its `focused_id`, via `hit_chain` (as a single-element
+    /// chain), mirroring how `Event::Click` is routed via the hit-test chain.
+    Key(KeyCode),
+}
+
+/// A position on the virtual canvas, as reported by `Component::cursor`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Position {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// The shape of the hardware cursor, as reported by `Component::cursor`. The
+/// caller is responsible for translating this into the backend's escape
+/// sequences (e.g. via `crossterm::cursor::SetCursorStyle`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum CursorKind {
+    /// A solid block, usually the terminal's default shape.
+    Block,
+    /// A vertical bar, as commonly used by text editors.
+    Bar,
+    /// A horizontal underline.
+    Underline,
+    /// The cursor should be hidden entirely.
+    Hidden,
+}
+
 /// A component which can be rendered on the virtual canvas. All calls to draw
 /// components are traced so that it can be determined later where a given
 /// component was drawn.
@@ -396,4 +932,266 @@ pub(crate) trait Component: Sized {
 
     /// Draw this component and any child components.
     fn draw(&self, viewport: &mut Viewport<Self::Id>, x: isize, y: isize);
+
+    /// Handle an event addressed to this component or one of its children.
+    /// `drawn_rects` is the mapping returned by the most recent call to
+    /// `Viewport::render_top_level`. `hit_chain` is precomputed by
+    /// `dispatch_event` so components don't need to hit-test or sort it
+    /// themselves: for `Event::Click`, it's the IDs whose recorded `Rect`
+    /// contains the click point, ordered innermost-to-outermost; for
+    /// `Event::Key`, it's the single focused ID (if any) passed as
+    /// `dispatch_event`'s `focused_id`.
+    ///
+    /// The default implementation ignores all events. Components with
+    /// interactive children should override this to forward the event to
+    /// whichever of their children owns the first ID in `hit_chain` that
+    /// belongs to one of them, falling back to handling the event themselves
+    /// (or ignoring it) if no child claims it.
+    fn handle_event(
+        &mut self,
+        event: Event,
+        drawn_rects: &HashMap<Self::Id, Rect>,
+        hit_chain: &[Self::Id],
+    ) -> EventResult {
+        let _ = (event, drawn_rects, hit_chain);
+        EventResult::Ignored
+    }
+
+    /// Where this component wants the hardware cursor placed, if anywhere.
+    /// `area` is the bounding box this component was just drawn into. Called
+    /// during `Viewport::render_top_level`; if multiple components return
+    /// `Some`, the one drawn topmost wins.
+    ///
+    /// The default implementation declines to place the cursor.
+    fn cursor(&self, area: Rect) -> Option<(Position, CursorKind)> {
+        let _ = area;
+        None
+    }
+
+    /// Whether this component needs to be redrawn this frame. If `false`,
+    /// `Viewport::draw_component` skips calling `draw` entirely — in both the
+    /// `Layout` and `Paint` passes, so the cost of `draw` itself is avoided,
+    /// not just its writes to the `Buffer` — and instead reuses this
+    /// component's cached geometry (bounding box, descendants' bounding
+    /// boxes, and hitboxes) from the last frame its subtree was actually
+    /// drawn. The component adds nothing to the damage `Rect` returned by
+    /// `render_top_level` itself; an enclosing component that is redrawn
+    /// still adds its whole bounding box, which contains this one.
+    ///
+    /// The default implementation always returns `true`; components backed
+    /// by cheap-to-check state (e.g. a diff that hasn't changed) should
+    /// override this to avoid redrawing unnecessarily large views. Since the
+    /// cached geometry is only refreshed the next time `draw` actually runs,
+    /// a component that returns `false` must not change size or position
+    /// relative to its parent's call to `draw_component` until it returns
+    /// `true` again.
+    fn should_update(&self) -> bool {
+        true
+    }
+}
+
+/// The IDs whose recorded bounding box in `drawn_rects` contains `(x, y)`,
+/// ordered innermost-to-outermost (smallest area first, with the `Rect`'s
+/// own fields as an explicit, deterministic tie-break — `drawn_rects` is a
+/// `HashMap`, so falling through to its iteration order would make the
+/// ordering of equal-area hits vary between runs). Nested components'
+/// `Rect`s are expected to nest inside their ancestors', so area is a good
+/// proxy for depth without needing the actual component tree structure;
+/// distinct components whose `Rect`s are identical (e.g. a zero-padding
+/// wrapper around its one child) can't be ordered by geometry alone, so they
+/// keep `drawn_rects`' iteration order, which is not deterministic.
+fn hit_test_chain<Id: Clone>(drawn_rects: &HashMap<Id, Rect>, x: isize, y: isize) -> Vec<Id> {
+    let point = Rect {
+        x,
+        y,
+        width: 1,
+        height: 1,
+    };
+    let mut hits: Vec<(Id, Rect)> = drawn_rects
+        .iter()
+        .filter(|(_id, rect)| !rect.intersect(point).is_empty())
+        .map(|(id, rect)| (id.clone(), *rect))
+        .collect();
+    hits.sort_by_key(|(_id, rect)| {
+        (
+            rect.width * rect.height,
+            rect.x,
+            rect.y,
+            rect.width,
+            rect.height,
+        )
+    });
+    hits.into_iter().map(|(id, _rect)| id).collect()
+}
+
+/// Route `event` to `component` (or one of its descendants). `drawn_rects`
+/// should be the mapping returned by `Viewport::render_top_level` for the
+/// frame that was just drawn. `focused_id` is the component ID that should
+/// receive `Event::Key` events, if any (e.g. the ID of whichever component
+/// the caller considers focused); it's ignored for `Event::Click`.
+///
+/// For `Event::Click`, this computes `hit_chain` — the IDs whose recorded
+/// bounding box contains the click point, innermost-to-outermost — and hands
+/// it to `Component::handle_event`, so the walk from the deepest
+/// clicked-through component up towards the root doesn't need to be
+/// hand-rolled by every component that forwards events to children. For
+/// `Event::Key`, `hit_chain` is just `focused_id` (if given), as a
+/// single-element chain.
+pub(crate) fn dispatch_event<C: Component>(
+    component: &mut C,
+    drawn_rects: &HashMap<C::Id, Rect>,
+    focused_id: Option<&C::Id>,
+    event: Event,
+) -> EventResult {
+    let hit_chain = match event {
+        Event::Click { x, y } => hit_test_chain(drawn_rects, x, y),
+        Event::Key(_) => focused_id.cloned().into_iter().collect(),
+    };
+    component.handle_event(event, drawn_rects, &hit_chain)
+}
+
+impl<ComponentId: Clone + Debug + Eq + Hash> Viewport<'_, ComponentId> {
+    /// Whether the component identified by `id`'s recorded bounding box (as
+    /// found in `drawn_rects`) contains the point `(x, y)`. Child components
+    /// should use this to decide whether to forward a click event to one of
+    /// their children.
+    pub fn rect_contains(
+        drawn_rects: &HashMap<ComponentId, Rect>,
+        id: &ComponentId,
+        x: isize,
+        y: isize,
+    ) -> bool {
+        let point = Rect {
+            x,
+            y,
+            width: 1,
+            height: 1,
+        };
+        drawn_rects
+            .get(id)
+            .map(|rect| !rect.intersect(point).is_empty())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clip_to_columns_wide_char_straddles_left_edge() {
+        // Columns: "a" -> 0, "本" -> 1..3, "b" -> 3.
+        let content = "a本b";
+        let (byte_range, left_pad, right_pad) = clip_to_columns(content, 2, 4);
+        assert_eq!(&content[byte_range], "b");
+        assert_eq!(left_pad, 1);
+        assert_eq!(right_pad, 0);
+    }
+
+    #[test]
+    fn test_clip_to_columns_wide_char_straddles_right_edge() {
+        let content = "a本b";
+        let (byte_range, left_pad, right_pad) = clip_to_columns(content, 0, 2);
+        assert_eq!(&content[byte_range], "a");
+        assert_eq!(left_pad, 0);
+        assert_eq!(right_pad, 1);
+    }
+
+    #[test]
+    fn test_clip_to_columns_exact_bounds_no_padding() {
+        let content = "a本b";
+        let (byte_range, left_pad, right_pad) = clip_to_columns(content, 0, 4);
+        assert_eq!(&content[byte_range], "a本b");
+        assert_eq!(left_pad, 0);
+        assert_eq!(right_pad, 0);
+    }
+
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    enum TestId {
+        Outer,
+        Inner,
+    }
+
+    struct OuterComponent;
+
+    impl Component for OuterComponent {
+        type Id = TestId;
+
+        fn id(&self) -> Self::Id {
+            TestId::Outer
+        }
+
+        fn draw(&self, viewport: &mut Viewport<Self::Id>, x: isize, y: isize) {
+            viewport.draw_component(x, y, &InnerComponent);
+        }
+
+        fn cursor(&self, area: Rect) -> Option<(Position, CursorKind)> {
+            Some((
+                Position {
+                    x: area.x,
+                    y: area.y,
+                },
+                CursorKind::Block,
+            ))
+        }
+    }
+
+    struct InnerComponent;
+
+    impl Component for InnerComponent {
+        type Id = TestId;
+
+        fn id(&self) -> Self::Id {
+            TestId::Inner
+        }
+
+        fn draw(&self, viewport: &mut Viewport<Self::Id>, x: isize, y: isize) {
+            viewport.draw_span(x, y, &Span::raw("x"));
+        }
+
+        fn cursor(&self, area: Rect) -> Option<(Position, CursorKind)> {
+            Some((
+                Position {
+                    x: area.x,
+                    y: area.y,
+                },
+                CursorKind::Bar,
+            ))
+        }
+    }
+
+    #[test]
+    fn test_cursor_prefers_innermost_component() {
+        let mut buf = Buffer::empty(tui::layout::Rect {
+            x: 0,
+            y: 0,
+            width: 10,
+            height: 10,
+        });
+        let mut viewport = Viewport::<TestId> {
+            buf: &mut buf,
+            rect: Rect {
+                x: 0,
+                y: 0,
+                width: 10,
+                height: 10,
+            },
+            trace: vec![Default::default()],
+            debug_messages: Default::default(),
+            mode: ViewportMode::Paint,
+            hitboxes: Default::default(),
+            mouse_position: None,
+            cursor: None,
+            origin: (0, 0),
+            layers: Default::default(),
+            previous: None,
+            damage_cache: Default::default(),
+            dirty: Default::default(),
+        };
+        viewport.draw_component(0, 0, &OuterComponent);
+        assert_eq!(
+            viewport.cursor,
+            Some((Position { x: 0, y: 0 }, CursorKind::Bar)),
+        );
+    }
 }